@@ -6,6 +6,7 @@ pub mod codex;
 pub(crate) mod command_install;
 pub(crate) mod json_hooks;
 pub mod learn;
+pub mod mcp;
 pub(crate) mod setup_command;
 pub(crate) mod skill_install;
 pub mod syntaxtree;
@@ -0,0 +1,322 @@
+//! Model Context Protocol stdio server exposing Nudge rules.
+//!
+//! This lets agents other than the Claude Code/Codex hook integrations (or
+//! Claude itself, mid-conversation, over MCP) query the project's Nudge
+//! rules directly instead of only discovering them by triggering a hook.
+//! We hand-roll the JSON-RPC framing here rather than pulling in an MCP SDK,
+//! matching how the rest of Nudge normalizes provider protocols by hand (see
+//! `agent::claude` and `agent::codex`).
+
+use std::io::{self, BufRead, Write};
+
+use clap::Args;
+use color_eyre::eyre::{Context, Result};
+use serde_json::{Value, json};
+
+use nudge::rules::{self, Hook, PreToolUseMatcher, Rule, RuleAction};
+
+#[derive(Args, Clone, Debug)]
+pub struct Config {}
+
+const PROTOCOL_VERSION: &str = "2024-11-05";
+
+/// Run the MCP stdio server until stdin closes.
+pub fn main(_config: Config) -> Result<()> {
+    let stdin = io::stdin();
+    let mut stdout = io::stdout();
+
+    for line in stdin.lock().lines() {
+        let line = line.context("read MCP request line")?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let request: Value = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(error) => {
+                tracing::warn!(%error, "failed to parse MCP request");
+                continue;
+            }
+        };
+
+        if let Some(response) = handle_request(&request) {
+            writeln!(stdout, "{response}").context("write MCP response")?;
+            stdout.flush().context("flush MCP response")?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Dispatch a single JSON-RPC request. Returns `None` for notifications,
+/// which do not get a response.
+fn handle_request(request: &Value) -> Option<String> {
+    let id = request.get("id").cloned();
+    let method = request.get("method").and_then(Value::as_str)?;
+
+    // Requests carry an `id`; notifications (like `notifications/initialized`)
+    // do not, and must not receive a response.
+    let id = id?;
+
+    let params = request.get("params").cloned().unwrap_or(Value::Null);
+    let result = match method {
+        "initialize" => Ok(initialize_result()),
+        "resources/list" => Ok(resources_list_result()),
+        "resources/read" => resources_read_result(&params),
+        "tools/list" => Ok(tools_list_result()),
+        "tools/call" => tools_call_result(&params),
+        other => Err(format!("unknown method: {other}")),
+    };
+
+    Some(render_response(id, result))
+}
+
+fn render_response(id: Value, result: Result<Value, String>) -> String {
+    let response = match result {
+        Ok(result) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "result": result,
+        }),
+        Err(message) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": -32000, "message": message },
+        }),
+    };
+
+    response.to_string()
+}
+
+fn initialize_result() -> Value {
+    json!({
+        "protocolVersion": PROTOCOL_VERSION,
+        "capabilities": {
+            "resources": {},
+            "tools": {},
+        },
+        "serverInfo": {
+            "name": "nudge",
+            "version": env!("NUDGE_VERSION"),
+        },
+    })
+}
+
+const RULES_RESOURCE_URI: &str = "nudge://rules";
+
+fn resources_list_result() -> Value {
+    json!({
+        "resources": [
+            {
+                "uri": RULES_RESOURCE_URI,
+                "name": "Active Nudge rules",
+                "description": "The rules Nudge would evaluate against hook events in this project.",
+                "mimeType": "application/json",
+            },
+        ],
+    })
+}
+
+fn resources_read_result(params: &Value) -> Result<Value, String> {
+    let uri = params
+        .get("uri")
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("missing uri"))?;
+
+    if uri != RULES_RESOURCE_URI {
+        return Err(format!("unknown resource: {uri}"));
+    }
+
+    let rules = rules::load_all().map_err(|error| format!("load rules: {error:#}"))?;
+    let summaries = rules.iter().map(rule_summary).collect::<Vec<_>>();
+    let text = serde_json::to_string_pretty(&summaries).map_err(|error| error.to_string())?;
+
+    Ok(json!({
+        "contents": [
+            {
+                "uri": RULES_RESOURCE_URI,
+                "mimeType": "application/json",
+                "text": text,
+            },
+        ],
+    }))
+}
+
+fn rule_summary(rule: &Rule) -> Value {
+    json!({
+        "name": rule.name,
+        "description": rule.description,
+        "action": rule.action,
+    })
+}
+
+fn tools_list_result() -> Value {
+    json!({
+        "tools": [
+            {
+                "name": "check_content",
+                "description": "Check file content against Nudge rules for a given path, without running the file through a provider hook.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "path": { "type": "string" },
+                        "content": { "type": "string" },
+                    },
+                    "required": ["path", "content"],
+                },
+            },
+            {
+                "name": "explain_rule",
+                "description": "Explain what a named Nudge rule checks for and what message it shows when it matches.",
+                "inputSchema": {
+                    "type": "object",
+                    "properties": {
+                        "name": { "type": "string" },
+                    },
+                    "required": ["name"],
+                },
+            },
+        ],
+    })
+}
+
+fn tools_call_result(params: &Value) -> Result<Value, String> {
+    let name = params
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("missing tool name"))?;
+    let arguments = params.get("arguments").cloned().unwrap_or(Value::Null);
+
+    let text = match name {
+        "check_content" => check_content(&arguments)?,
+        "explain_rule" => explain_rule(&arguments)?,
+        other => return Err(format!("unknown tool: {other}")),
+    };
+
+    Ok(json!({
+        "content": [{ "type": "text", "text": text }],
+    }))
+}
+
+fn check_content(arguments: &Value) -> Result<String, String> {
+    let path = arguments
+        .get("path")
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("missing path argument"))?;
+    let content = arguments
+        .get("content")
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("missing content argument"))?;
+
+    let rules = rules::load_all().map_err(|error| format!("load rules: {error:#}"))?;
+    let mut messages = Vec::new();
+
+    for rule in rules.iter().filter(|rule| rule.action == RuleAction::Block) {
+        for hook in &rule.on {
+            let Hook::PreToolUse(PreToolUseMatcher::Write(matcher)) = hook else {
+                continue;
+            };
+            if !matcher.file.is_match(path) {
+                continue;
+            }
+
+            let matches = matcher.target.evaluate(content, &matcher.content);
+            messages.extend(rule.annotate_matches(matches).map(|annotation| {
+                format!("[{}] {}", rule.name, annotation.label)
+            }));
+        }
+    }
+
+    if messages.is_empty() {
+        Ok(String::from("No rule violations found."))
+    } else {
+        Ok(messages.join("\n"))
+    }
+}
+
+fn explain_rule(arguments: &Value) -> Result<String, String> {
+    let name = arguments
+        .get("name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| String::from("missing name argument"))?;
+
+    let rules = rules::load_all().map_err(|error| format!("load rules: {error:#}"))?;
+    let rule = rules
+        .into_iter()
+        .find(|rule| rule.name == name)
+        .ok_or_else(|| format!("no rule named '{name}'"))?;
+
+    Ok(format!(
+        "Rule '{}'\nDescription: {}\nAction: {:?}\nMessage: {}",
+        rule.name,
+        rule.description.as_deref().unwrap_or("(none)"),
+        rule.action,
+        rule.message(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::handle_request;
+
+    #[test]
+    fn initialize_returns_protocol_version() {
+        let response = handle_request(&json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "initialize",
+            "params": {},
+        }))
+        .expect("initialize response");
+
+        let response: serde_json::Value = serde_json::from_str(&response).expect("valid json");
+        assert!(response["result"]["protocolVersion"].is_string());
+    }
+
+    #[test]
+    fn notifications_get_no_response() {
+        let response = handle_request(&json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/initialized",
+        }));
+
+        assert!(response.is_none());
+    }
+
+    #[test]
+    fn tools_list_includes_check_content_and_explain_rule() {
+        let response = handle_request(&json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": "tools/list",
+        }))
+        .expect("tools/list response");
+
+        let response: serde_json::Value = serde_json::from_str(&response).expect("valid json");
+        let names = response["result"]["tools"]
+            .as_array()
+            .expect("tools array")
+            .iter()
+            .filter_map(|tool| tool["name"].as_str())
+            .collect::<Vec<_>>();
+
+        assert!(names.contains(&"check_content"));
+        assert!(names.contains(&"explain_rule"));
+    }
+
+    #[test]
+    fn unknown_method_returns_error() {
+        let response = handle_request(&json!({
+            "jsonrpc": "2.0",
+            "id": 3,
+            "method": "not/a/method",
+        }))
+        .expect("error response");
+
+        let response: serde_json::Value = serde_json::from_str(&response).expect("valid json");
+        assert!(response["error"]["message"].is_string());
+    }
+}
@@ -31,6 +31,9 @@ enum Commands {
     /// Manage repo-local learned incident knowledge.
     Learn(cmd::learn::Config),
 
+    /// Run an MCP stdio server exposing Nudge rules as resources and tools.
+    Mcp(cmd::mcp::Config),
+
     /// Display the syntax tree for code (for writing tree-sitter queries).
     Syntaxtree(cmd::syntaxtree::Config),
 
@@ -85,6 +88,7 @@ fn main() -> Result<()> {
         Commands::Claude(config) => cmd::claude::main(config),
         Commands::Codex(config) => cmd::codex::main(config),
         Commands::Learn(config) => cmd::learn::main(config),
+        Commands::Mcp(config) => cmd::mcp::main(config),
         Commands::Syntaxtree(config) => cmd::syntaxtree::main(config),
         Commands::Validate(config) => cmd::validate::main(config),
         Commands::Test(config) => cmd::test::main(config),